@@ -1,5 +1,361 @@
 use crate::{core::Logs, Docker, Image};
 use std::env::var;
+use std::io;
+use std::io::BufRead;
+use std::net::IpAddr;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{mpsc, Arc};
+use std::thread;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+/// The default overall timeout for [`WaitFor`] strategies, unless overridden via the
+/// `WAIT_TIMEOUT_SECONDS` environment variable.
+///
+/// [`WaitFor`]: enum.WaitFor.html
+const DEFAULT_WAIT_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// The output stream a [`WaitFor::Message`] strategy should be matched against.
+///
+/// [`WaitFor::Message`]: enum.WaitFor.html#variant.Message
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LogSource {
+    Stdout,
+    Stderr,
+}
+
+/// A strategy for determining when a [`Container`] is ready to be used.
+///
+/// An [`Image`] returns a `Vec<WaitFor>` from [`Image::ready_conditions`]; [`Container::new`]
+/// applies each strategy in order before returning, so images no longer need to hand-roll their
+/// own polling.
+///
+/// [`Container`]: struct.Container.html
+/// [`Container::new`]: struct.Container.html#method.new
+/// [`Image`]: trait.Image.html
+/// [`Image::ready_conditions`]: trait.Image.html#method.ready_conditions
+#[derive(Debug, Clone)]
+pub enum WaitFor {
+    /// Waits until a line containing `text` appears on the given log stream.
+    Message { io: LogSource, text: String },
+    /// Waits until `docker inspect`'s `Health.Status` reports `healthy`.
+    Healthcheck,
+    /// Simply sleeps for the given duration.
+    Duration(Duration),
+}
+
+/// The `Health.Status` of a container, as reported by `docker inspect`.
+///
+/// Queried directly via [`Docker::health_status`] rather than through [`State`], since a
+/// container's health only matters to the [`WaitFor::Healthcheck`] strategy and doesn't belong on
+/// the general-purpose state snapshot.
+///
+/// [`Docker::health_status`]: trait.Docker.html#tymethod.health_status
+/// [`State`]: struct.State.html
+/// [`WaitFor::Healthcheck`]: enum.WaitFor.html#variant.Healthcheck
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HealthStatus {
+    Starting,
+    Healthy,
+    Unhealthy,
+}
+
+/// The status of a container, as reported by `docker inspect`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ContainerStatus {
+    Created,
+    Running,
+    Paused,
+    Restarting,
+    Exited,
+    Dead,
+}
+
+/// A snapshot of a [`Container`]'s state, as reported by `docker inspect`.
+///
+/// [`Container`]: struct.Container.html
+#[derive(Debug, Clone)]
+pub struct State {
+    status: ContainerStatus,
+    exit_code: Option<i64>,
+    oom_killed: bool,
+    pid: Option<i64>,
+    started_at: Option<String>,
+    finished_at: Option<String>,
+}
+
+impl State {
+    pub fn new(
+        status: ContainerStatus,
+        exit_code: Option<i64>,
+        oom_killed: bool,
+        pid: Option<i64>,
+        started_at: Option<String>,
+        finished_at: Option<String>,
+    ) -> Self {
+        State {
+            status,
+            exit_code,
+            oom_killed,
+            pid,
+            started_at,
+            finished_at,
+        }
+    }
+
+    pub fn status(&self) -> ContainerStatus {
+        self.status
+    }
+
+    pub fn exit_code(&self) -> Option<i64> {
+        self.exit_code
+    }
+
+    pub fn oom_killed(&self) -> bool {
+        self.oom_killed
+    }
+
+    pub fn pid(&self) -> Option<i64> {
+        self.pid
+    }
+
+    pub fn started_at(&self) -> Option<&str> {
+        self.started_at.as_deref()
+    }
+
+    pub fn finished_at(&self) -> Option<&str> {
+        self.finished_at.as_deref()
+    }
+
+    /// Returns `true` if and only if `status` is [`ContainerStatus::Exited`] with `exit_code`
+    /// equal to `Some(0)`.
+    ///
+    /// [`ContainerStatus::Exited`]: enum.ContainerStatus.html#variant.Exited
+    pub fn successful_exit(&self) -> bool {
+        self.status == ContainerStatus::Exited && self.exit_code == Some(0)
+    }
+}
+
+/// A command to run inside an already-running [`Container`] via [`Container::exec`].
+///
+/// [`Container`]: struct.Container.html
+/// [`Container::exec`]: struct.Container.html#method.exec
+#[derive(Debug, Clone)]
+pub struct ExecCommand {
+    cmd: Vec<String>,
+    envs: Vec<(String, String)>,
+    working_dir: Option<String>,
+}
+
+impl ExecCommand {
+    /// Creates a new command to be run with [`Container::exec`].
+    ///
+    /// [`Container::exec`]: struct.Container.html#method.exec
+    pub fn new(cmd: Vec<String>) -> Self {
+        ExecCommand {
+            cmd,
+            envs: Vec::new(),
+            working_dir: None,
+        }
+    }
+
+    /// Sets an environment variable for the executed command.
+    pub fn with_env(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.envs.push((key.into(), value.into()));
+        self
+    }
+
+    /// Runs the command in `working_dir` instead of the container's default working directory.
+    pub fn with_working_dir(mut self, working_dir: impl Into<String>) -> Self {
+        self.working_dir = Some(working_dir.into());
+        self
+    }
+
+    pub fn cmd(&self) -> &[String] {
+        &self.cmd
+    }
+
+    pub fn envs(&self) -> &[(String, String)] {
+        &self.envs
+    }
+
+    pub fn working_dir(&self) -> Option<&str> {
+        self.working_dir.as_deref()
+    }
+}
+
+/// The outcome of running an [`ExecCommand`] inside a [`Container`].
+///
+/// [`ExecCommand`]: struct.ExecCommand.html
+/// [`Container`]: struct.Container.html
+#[derive(Debug, Clone)]
+pub struct ExecResult {
+    stdout: Vec<u8>,
+    stderr: Vec<u8>,
+    exit_code: i64,
+}
+
+impl ExecResult {
+    pub fn new(stdout: Vec<u8>, stderr: Vec<u8>, exit_code: i64) -> Self {
+        ExecResult {
+            stdout,
+            stderr,
+            exit_code,
+        }
+    }
+
+    pub fn stdout(&self) -> &[u8] {
+        &self.stdout
+    }
+
+    pub fn stderr(&self) -> &[u8] {
+        &self.stderr
+    }
+
+    /// Returns the exit code of the executed command.
+    ///
+    /// A non-zero exit code is surfaced here rather than silently treated as success, so tests
+    /// can assert on it directly.
+    pub fn exit_code(&self) -> i64 {
+        self.exit_code
+    }
+
+    /// Returns `true` if the executed command exited with code `0`.
+    pub fn success(&self) -> bool {
+        self.exit_code == 0
+    }
+}
+
+/// Configuration for a `docker build` invocation: the build context directory, the Dockerfile
+/// name within it, and any `--build-arg`s.
+///
+/// [`BuildContext::build_and_tag`] runs the build and returns the resulting image tag, which can
+/// be used as the name of any [`Image`] passed to `Docker::run` like a pulled tag would be.
+///
+/// [`BuildContext::build_and_tag`]: struct.BuildContext.html#method.build_and_tag
+/// [`Image`]: trait.Image.html
+#[derive(Debug, Clone)]
+pub struct BuildContext {
+    context: PathBuf,
+    dockerfile: String,
+    build_args: Vec<(String, String)>,
+}
+
+impl BuildContext {
+    /// Creates a build context rooted at `context`, using the default `Dockerfile` name.
+    pub fn from_dockerfile(context: impl Into<PathBuf>) -> Self {
+        BuildContext {
+            context: context.into(),
+            dockerfile: "Dockerfile".into(),
+            build_args: Vec::new(),
+        }
+    }
+
+    /// Builds from `dockerfile` (a path relative to the build context) instead of the default
+    /// `Dockerfile`.
+    pub fn with_dockerfile_name(mut self, dockerfile: impl Into<String>) -> Self {
+        self.dockerfile = dockerfile.into();
+        self
+    }
+
+    /// Adds a `--build-arg key=value` to the `docker build` invocation.
+    pub fn with_build_arg(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.build_args.push((key.into(), value.into()));
+        self
+    }
+
+    pub fn context(&self) -> &Path {
+        &self.context
+    }
+
+    pub fn dockerfile(&self) -> &str {
+        &self.dockerfile
+    }
+
+    pub fn build_args(&self) -> &[(String, String)] {
+        &self.build_args
+    }
+
+    /// Builds this context with `docker build` and returns the resulting image tag.
+    ///
+    /// `docker build` is invoked directly against [`context`], which tars the context itself; the
+    /// configured Dockerfile name and build args are passed through as `-f`/`--build-arg`. The
+    /// image is tagged with a name unique to this invocation, which this method returns on
+    /// success.
+    ///
+    /// [`context`]: struct.BuildContext.html#method.context
+    pub fn build_and_tag(&self) -> io::Result<String> {
+        let tag = format!(
+            "testcontainers-build-{}",
+            SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .unwrap_or_default()
+                .as_nanos()
+        );
+
+        let mut command = Command::new("docker");
+        command
+            .arg("build")
+            .arg("-f")
+            .arg(self.context.join(&self.dockerfile))
+            .arg("-t")
+            .arg(&tag);
+
+        for (key, value) in &self.build_args {
+            command.arg("--build-arg").arg(format!("{}={}", key, value));
+        }
+
+        command.arg(&self.context);
+
+        log::debug!("Building image from {:?} as {}", self.context, tag);
+
+        let status = command.status()?;
+
+        if !status.success() {
+            return Err(io::Error::other(format!(
+                "docker build for {:?} failed with {}",
+                self.context, status
+            )));
+        }
+
+        Ok(tag)
+    }
+}
+
+/// The kind of filesystem change reported for a path in [`Container::fs_changes`].
+///
+/// [`Container::fs_changes`]: struct.Container.html#method.fs_changes
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FsChangeKind {
+    Added,
+    Modified,
+    Deleted,
+}
+
+/// A single filesystem change between a [`Container`] and its image, as reported by docker's
+/// container changes endpoint.
+///
+/// [`Container`]: struct.Container.html
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FsChange {
+    path: String,
+    kind: FsChangeKind,
+}
+
+impl FsChange {
+    pub fn new(path: String, kind: FsChangeKind) -> Self {
+        FsChange { path, kind }
+    }
+
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    pub fn kind(&self) -> FsChangeKind {
+        self.kind
+    }
+}
 
 /// Represents a running docker container.
 ///
@@ -39,9 +395,10 @@ where
 {
     /// Constructs a new container given an id, a docker client and the image.
     ///
-    /// This function will block the current thread (if [`wait_until_ready`] is implemented correctly) until the container is actually ready to be used.
+    /// This function will block the current thread, applying each of the image's
+    /// [`ready_conditions`] in turn, until the container is actually ready to be used.
     ///
-    /// [`wait_until_ready`]: trait.Image.html#tymethod.wait_until_ready
+    /// [`ready_conditions`]: trait.Image.html#method.ready_conditions
     pub fn new(id: String, docker_client: &'d D, image: I) -> Self {
         let container = Container {
             id,
@@ -96,6 +453,69 @@ where
         resolved_port
     }
 
+    /// Executes a command inside this running container and returns its captured output.
+    ///
+    /// This creates a docker exec instance, runs it to completion and captures stdout, stderr
+    /// and the exit code. A non-zero exit code is returned as part of the [`ExecResult`] rather
+    /// than treated as an error, so callers can assert on it (e.g. seeding a database or checking
+    /// for the presence of a file).
+    ///
+    /// [`ExecResult`]: struct.ExecResult.html
+    pub fn exec(&self, cmd: ExecCommand) -> ExecResult {
+        log::debug!("Executing command {:?} in container {}", cmd, self.id);
+
+        self.docker_client.exec(&self.id, cmd)
+    }
+
+    /// Returns the IP address of this container on the default docker bridge network.
+    ///
+    /// This reads the `NetworkSettings` of the inspect response. Unlike [`get_host_port`], this
+    /// address is reachable from other containers on the same docker network without requiring
+    /// the internal port to be published to the host.
+    ///
+    /// Like [`get_host_port`], this is a thin wrapper around a docker client lookup with no
+    /// independent logic of its own, so it has no unit tests (same as [`get_host_port`]).
+    ///
+    /// [`get_host_port`]: struct.Container.html#method.get_host_port
+    pub fn get_bridge_ip_address(&self) -> IpAddr {
+        self.docker_client.bridge_ip_address(&self.id)
+    }
+
+    /// Returns the IP address of this container on the given user-defined network, if the
+    /// container is attached to it.
+    ///
+    /// This is useful for multi-container topologies where one container needs to connect
+    /// directly to another over a docker network, rather than through a mapped host port.
+    ///
+    /// As with [`get_bridge_ip_address`], this only forwards to the docker client and has no
+    /// independent logic of its own, so it has no unit tests.
+    ///
+    /// [`get_bridge_ip_address`]: struct.Container.html#method.get_bridge_ip_address
+    pub fn get_container_ip(&self, network: &str) -> Option<IpAddr> {
+        self.docker_client.container_ip_address(&self.id, network)
+    }
+
+    /// Returns a snapshot of this container's state (status, exit code, whether it was
+    /// OOM-killed, and its started/finished timestamps).
+    ///
+    /// This is backed by a docker inspect call. See [`State::successful_exit`] for a convenient
+    /// way to assert that a short-lived container completed successfully.
+    ///
+    /// [`State::successful_exit`]: struct.State.html#method.successful_exit
+    pub fn state(&self) -> State {
+        self.docker_client.state(&self.id)
+    }
+
+    /// Returns the paths added, modified or deleted in this container's filesystem relative to
+    /// its image, as reported by docker's container changes endpoint.
+    ///
+    /// Pairs naturally with [`exec`]: run a command, then assert on the paths it touched.
+    ///
+    /// [`exec`]: struct.Container.html#method.exec
+    pub fn fs_changes(&self) -> Vec<FsChange> {
+        self.docker_client.fs_changes(&self.id)
+    }
+
     /// Returns a reference to the [`Image`] of this container.
     ///
     /// Access to this is useful if the [`arguments`] of the [`Image`] change how to connect to the
@@ -110,11 +530,127 @@ where
     fn block_until_ready(&self) {
         log::debug!("Waiting for container {} to be ready", self.id);
 
-        self.image.wait_until_ready(self);
+        let timeout = var("WAIT_TIMEOUT_SECONDS")
+            .ok()
+            .and_then(|var| var.parse().ok())
+            .map(Duration::from_secs)
+            .unwrap_or(DEFAULT_WAIT_TIMEOUT);
+        let deadline = Instant::now() + timeout;
+
+        for wait_for in self.image.ready_conditions() {
+            match wait_for {
+                WaitFor::Message { io, text } => self.wait_for_message(io, &text, deadline),
+                WaitFor::Healthcheck => self.wait_for_healthy(deadline),
+                WaitFor::Duration(duration) => self.wait_for_duration(duration, deadline),
+            }
+        }
 
         log::debug!("Container {} is now ready!", self.id);
     }
 
+    /// Sleeps for `duration`, bounded by `deadline` so a `WaitFor::Duration` strategy can't blow
+    /// through the overall wait timeout on its own.
+    fn wait_for_duration(&self, duration: Duration, deadline: Instant) {
+        let remaining = deadline.saturating_duration_since(Instant::now());
+
+        if duration > remaining {
+            panic!(
+                "container {} could not sleep for {:?} within the wait timeout",
+                self.id, duration
+            );
+        }
+
+        thread::sleep(duration);
+    }
+
+    fn wait_for_message(&self, io: LogSource, text: &str, deadline: Instant) {
+        let logs = self.logs();
+        let mut reader: Box<dyn BufRead + Send> = match io {
+            LogSource::Stdout => Box::new(logs.stdout),
+            LogSource::Stderr => Box::new(logs.stderr),
+        };
+
+        // `read_line` blocks until a line arrives or the stream closes, which can be forever for
+        // a quiet `docker logs --follow` pipe. Read on a background thread and poll it with
+        // `recv_timeout` so the overall deadline is still honored, and signal `cancelled` once we
+        // stop waiting so the thread gives up its next time `read_line` returns, instead of
+        // looping forever in the background.
+        let cancelled = Arc::new(AtomicBool::new(false));
+        let thread_cancelled = Arc::clone(&cancelled);
+        let text_owned = text.to_owned();
+        let (tx, rx) = mpsc::channel();
+        thread::spawn(move || {
+            let mut line = String::new();
+            while !thread_cancelled.load(Ordering::Relaxed) {
+                line.clear();
+
+                match reader.read_line(&mut line) {
+                    Ok(_) if line.contains(&text_owned) => {
+                        let _ = tx.send(Ok(()));
+                        return;
+                    }
+                    Ok(0) => thread::sleep(Duration::from_millis(100)),
+                    Ok(_) => {}
+                    Err(error) => {
+                        let _ = tx.send(Err(error.to_string()));
+                        return;
+                    }
+                }
+            }
+        });
+
+        let result = loop {
+            let remaining = deadline.saturating_duration_since(Instant::now());
+
+            if remaining.is_zero() {
+                break Err(format!(
+                    "container {} did not log a line containing {:?} within the wait timeout",
+                    self.id, text
+                ));
+            }
+
+            match rx.recv_timeout(remaining.min(Duration::from_millis(200))) {
+                Ok(Ok(())) => break Ok(()),
+                Ok(Err(error)) => {
+                    break Err(format!(
+                        "failed to read logs of container {} while waiting for {:?}: {}",
+                        self.id, text, error
+                    ))
+                }
+                Err(mpsc::RecvTimeoutError::Timeout) => {}
+                Err(mpsc::RecvTimeoutError::Disconnected) => {
+                    break Err(format!(
+                        "log reader for container {} stopped unexpectedly while waiting for {:?}",
+                        self.id, text
+                    ))
+                }
+            }
+        };
+
+        cancelled.store(true, Ordering::Relaxed);
+
+        if let Err(message) = result {
+            panic!("{}", message);
+        }
+    }
+
+    fn wait_for_healthy(&self, deadline: Instant) {
+        loop {
+            if self.docker_client.health_status(&self.id) == Some(HealthStatus::Healthy) {
+                return;
+            }
+
+            if Instant::now() >= deadline {
+                panic!(
+                    "container {} did not become healthy within the wait timeout",
+                    self.id
+                );
+            }
+
+            thread::sleep(Duration::from_millis(500));
+        }
+    }
+
     pub fn stop(&self) {
         log::debug!("Stopping docker container {}", self.id);
 
@@ -153,3 +689,93 @@ where
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn exec_command_has_no_envs_or_working_dir_by_default() {
+        let command = ExecCommand::new(vec!["true".to_string()]);
+
+        assert_eq!(command.cmd(), &["true".to_string()]);
+        assert!(command.envs().is_empty());
+        assert_eq!(command.working_dir(), None);
+    }
+
+    #[test]
+    fn exec_command_builder_sets_envs_and_working_dir() {
+        let command = ExecCommand::new(vec!["echo".to_string(), "hi".to_string()])
+            .with_env("FOO", "bar")
+            .with_env("BAZ", "qux")
+            .with_working_dir("/tmp");
+
+        assert_eq!(
+            command.envs(),
+            &[
+                ("FOO".to_string(), "bar".to_string()),
+                ("BAZ".to_string(), "qux".to_string())
+            ]
+        );
+        assert_eq!(command.working_dir(), Some("/tmp"));
+    }
+
+    #[test]
+    fn exec_result_success_reflects_zero_exit_code() {
+        let result = ExecResult::new(b"out".to_vec(), b"err".to_vec(), 0);
+
+        assert!(result.success());
+        assert_eq!(result.exit_code(), 0);
+        assert_eq!(result.stdout(), b"out");
+        assert_eq!(result.stderr(), b"err");
+    }
+
+    #[test]
+    fn exec_result_success_is_false_for_non_zero_exit_code() {
+        let result = ExecResult::new(Vec::new(), Vec::new(), 127);
+
+        assert!(!result.success());
+        assert_eq!(result.exit_code(), 127);
+    }
+
+    fn state_with(status: ContainerStatus, exit_code: Option<i64>) -> State {
+        State::new(status, exit_code, false, None, None, None)
+    }
+
+    #[test]
+    fn successful_exit_is_true_for_exited_with_code_zero() {
+        let state = state_with(ContainerStatus::Exited, Some(0));
+
+        assert!(state.successful_exit());
+    }
+
+    #[test]
+    fn successful_exit_is_false_for_non_zero_exit_code() {
+        let state = state_with(ContainerStatus::Exited, Some(1));
+
+        assert!(!state.successful_exit());
+    }
+
+    #[test]
+    fn successful_exit_is_false_while_still_running() {
+        let state = state_with(ContainerStatus::Running, None);
+
+        assert!(!state.successful_exit());
+    }
+
+    #[test]
+    fn fs_change_exposes_path_and_kind() {
+        let change = FsChange::new("/etc/passwd".to_string(), FsChangeKind::Modified);
+
+        assert_eq!(change.path(), "/etc/passwd");
+        assert_eq!(change.kind(), FsChangeKind::Modified);
+    }
+
+    #[test]
+    fn fs_changes_of_different_kind_are_not_equal() {
+        let added = FsChange::new("/tmp/a".to_string(), FsChangeKind::Added);
+        let deleted = FsChange::new("/tmp/a".to_string(), FsChangeKind::Deleted);
+
+        assert_ne!(added, deleted);
+    }
+}